@@ -0,0 +1,459 @@
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::CharDurations;
+
+/// Error returned by [`parse_duration`] when a human-friendly duration string
+/// (e.g. `"1s 500ms"`) could not be parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseDurationError {
+    /// A character that is neither a digit, a unit letter, nor whitespace was found at `offset`,
+    /// or a number was not directly followed by a unit letter (then `offset` is where the unit
+    /// was expected: the following character, such as a space, or the end of the string).
+    InvalidCharacter { offset: usize },
+    /// A unit (`ms`, `s`, ...) appeared without a preceding number, starting at `offset`.
+    MissingNumber { offset: usize },
+    /// The unit token spanning `start..end` isn't one of the recognized units.
+    UnknownUnit { start: usize, end: usize },
+    /// The accumulated duration overflowed.
+    Overflow,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseDurationError::InvalidCharacter { offset } => {
+                write!(f, "invalid character at byte offset {offset}")
+            }
+            ParseDurationError::MissingNumber { offset } => {
+                write!(f, "missing number before unit at byte offset {offset}")
+            }
+            ParseDurationError::UnknownUnit { start, end } => {
+                write!(f, "unknown unit at byte offset {start}..{end}")
+            }
+            ParseDurationError::Overflow => write!(f, "duration value overflowed"),
+        }
+    }
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// Parses a human-friendly duration string such as `"1s 500ms"` or `"90ms"` into a [`Duration`].
+///
+/// The string is a sequence of `<number><unit>` pairs, optionally separated by whitespace, that
+/// are summed together. Recognized units are `ns`, `us`/`µs`, `ms`, `s`/`sec`, `m`/`min`, and
+/// `h`/`hour`.
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::parse_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(parse_duration("1s 500ms").unwrap(), Duration::from_millis(1500));
+/// assert_eq!(parse_duration("90ms").unwrap(), Duration::from_millis(90));
+/// ```
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let mut total_nanos: u128 = 0;
+    let mut chars = s.char_indices().peekable();
+
+    while let Some(&(start, ch)) = chars.peek() {
+        if ch.is_whitespace() {
+            chars.next();
+            continue;
+        }
+
+        if ch.is_alphabetic() {
+            return Err(ParseDurationError::MissingNumber { offset: start });
+        }
+
+        if !ch.is_ascii_digit() {
+            return Err(ParseDurationError::InvalidCharacter { offset: start });
+        }
+
+        let mut value: u128 = 0;
+        while let Some(&(pos, ch)) = chars.peek() {
+            match ch.to_digit(10) {
+                Some(digit) => {
+                    value = value
+                        .checked_mul(10)
+                        .and_then(|v| v.checked_add(digit as u128))
+                        .ok_or(ParseDurationError::Overflow)?;
+                    chars.next();
+                }
+                None => {
+                    let _ = pos;
+                    break;
+                }
+            }
+        }
+
+        let unit_start = chars.peek().map_or(s.len(), |&(idx, _)| idx);
+        let mut unit_end = unit_start;
+        while let Some(&(idx, ch)) = chars.peek() {
+            if ch.is_alphabetic() {
+                chars.next();
+                unit_end = idx + ch.len_utf8();
+            } else {
+                break;
+            }
+        }
+
+        if unit_start == unit_end {
+            return Err(ParseDurationError::InvalidCharacter { offset: unit_start });
+        }
+
+        let unit = &s[unit_start..unit_end];
+        let nanos_per_unit: u128 = match unit {
+            "ns" => 1,
+            "us" | "µs" => 1_000,
+            "ms" => 1_000_000,
+            "s" | "sec" => 1_000_000_000,
+            "m" | "min" => 60_000_000_000,
+            "h" | "hour" => 3_600_000_000_000,
+            _ => {
+                return Err(ParseDurationError::UnknownUnit {
+                    start: unit_start,
+                    end: unit_end,
+                })
+            }
+        };
+
+        total_nanos = value
+            .checked_mul(nanos_per_unit)
+            .and_then(|n| total_nanos.checked_add(n))
+            .ok_or(ParseDurationError::Overflow)?;
+    }
+
+    let secs =
+        u64::try_from(total_nanos / 1_000_000_000).map_err(|_| ParseDurationError::Overflow)?;
+    let nanos = (total_nanos % 1_000_000_000) as u32;
+    Ok(Duration::new(secs, nanos))
+}
+
+/// Error returned by [`CharDurations`]'s [`FromStr`] impl when a config line like
+/// `default=90ms, ' '=250ms, '.'=1s` could not be parsed.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CharDurationsParseError {
+    /// An entry wasn't in `key=duration` form.
+    InvalidEntry(String),
+    /// An entry's key wasn't `default` or a single-quoted character.
+    InvalidChar(String),
+    /// An entry's duration couldn't be parsed.
+    Duration(ParseDurationError),
+}
+
+impl fmt::Display for CharDurationsParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CharDurationsParseError::InvalidEntry(entry) => {
+                write!(f, "invalid entry {entry:?}, expected `key=duration`")
+            }
+            CharDurationsParseError::InvalidChar(key) => {
+                write!(f, "invalid key {key:?}, expected `default` or a quoted char")
+            }
+            CharDurationsParseError::Duration(e) => write!(f, "invalid duration: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for CharDurationsParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            CharDurationsParseError::Duration(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<ParseDurationError> for CharDurationsParseError {
+    fn from(e: ParseDurationError) -> Self {
+        CharDurationsParseError::Duration(e)
+    }
+}
+
+/// Parses a config line such as `default=90ms, ' '=250ms, '.'=1s` into a [`CharDurations`].
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::CharDurations;
+/// use std::time::Duration;
+///
+/// let d: CharDurations = "default=90ms, ' '=250ms, '.'=1s".parse().unwrap();
+/// assert_eq!(*d.duration('a'), Duration::from_millis(90));
+/// assert_eq!(*d.duration(' '), Duration::from_millis(250));
+/// assert_eq!(*d.duration('.'), Duration::from_secs(1));
+/// ```
+impl FromStr for CharDurations {
+    type Err = CharDurationsParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut default_duration = Duration::ZERO;
+        let mut specific_duration = std::collections::HashMap::new();
+
+        for entry in split_entries(s) {
+            let entry = entry.trim();
+            if entry.is_empty() {
+                continue;
+            }
+
+            if let Some(rest) = entry.strip_prefix("default") {
+                let value = strip_key_value_separator(rest)
+                    .ok_or_else(|| CharDurationsParseError::InvalidEntry(entry.to_owned()))?;
+                default_duration = parse_duration(value)?;
+                continue;
+            }
+
+            let (ch, rest) = parse_quoted_char(entry)
+                .ok_or_else(|| CharDurationsParseError::InvalidChar(entry.to_owned()))?;
+            let value = strip_key_value_separator(rest)
+                .ok_or_else(|| CharDurationsParseError::InvalidEntry(entry.to_owned()))?;
+
+            specific_duration.insert(ch, parse_duration(value)?);
+        }
+
+        Ok(CharDurations::new(default_duration, specific_duration))
+    }
+}
+
+/// Splits a config string into its top-level `,`-separated entries, ignoring any `,` that
+/// appears inside a quoted char key (e.g. `'<char>'`), so a specific-char entry for `,` itself
+/// isn't sliced in half.
+fn split_entries(s: &str) -> Vec<&str> {
+    let mut entries = Vec::new();
+    let mut start = 0;
+    let mut in_quote = false;
+    let mut chars = s.char_indices();
+
+    while let Some((idx, ch)) = chars.next() {
+        if in_quote {
+            match ch {
+                '\\' => {
+                    chars.next();
+                }
+                '\'' => in_quote = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match ch {
+            '\'' => in_quote = true,
+            ',' => {
+                entries.push(&s[start..idx]);
+                start = idx + ch.len_utf8();
+            }
+            _ => {}
+        }
+    }
+
+    entries.push(&s[start..]);
+    entries
+}
+
+/// Parses a leading `'<char>'` key off the front of an entry, returning the char and the
+/// remaining, unparsed tail of the entry.
+///
+/// Inverts the escaping done by [`CharDurations`]'s [`Display`](fmt::Display) impl: a backslash
+/// introduces an escape (`\'`, `\\`, `\n`, `\r`, `\t`), anything else is taken literally.
+fn parse_quoted_char(entry: &str) -> Option<(char, &str)> {
+    let mut chars = entry.char_indices();
+    let (0, '\'') = chars.next()? else {
+        return None;
+    };
+
+    let (first_idx, first) = chars.next()?;
+    let (ch, content_end) = if first == '\\' {
+        let (esc_idx, escaped) = chars.next()?;
+        let unescaped = match escaped {
+            '\'' => '\'',
+            '\\' => '\\',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            _ => return None,
+        };
+        (unescaped, esc_idx + escaped.len_utf8())
+    } else {
+        (first, first_idx + first.len_utf8())
+    };
+
+    let rest = entry.get(content_end..)?.strip_prefix('\'')?;
+    Some((ch, rest))
+}
+
+/// Writes `ch` into the interior of a `'...'` key, escaping the characters that would otherwise
+/// be ambiguous with the surrounding quotes or unreadable inline. Mirrored by [`parse_quoted_char`].
+fn write_escaped_char(ch: char, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match ch {
+        '\'' => write!(f, "\\'"),
+        '\\' => write!(f, "\\\\"),
+        '\n' => write!(f, "\\n"),
+        '\r' => write!(f, "\\r"),
+        '\t' => write!(f, "\\t"),
+        _ => write!(f, "{ch}"),
+    }
+}
+
+/// Strips the `=` (or plain whitespace) separator between a key and its duration value, e.g.
+/// `"=90ms"` or `" 90ms"` both become `"90ms"`.
+fn strip_key_value_separator(rest: &str) -> Option<&str> {
+    let rest = rest.trim_start().strip_prefix('=').unwrap_or(rest).trim();
+    (!rest.is_empty()).then_some(rest)
+}
+
+/// Formats a [`Duration`] into a humantime-style string using the largest sensible compound
+/// units, e.g. `"1s 500ms"` or `"250ms"`, emitting `"0s"` for a zero duration.
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::format_duration;
+/// use std::time::Duration;
+///
+/// assert_eq!(format_duration(Duration::from_millis(1500)), "1s 500ms");
+/// assert_eq!(format_duration(Duration::from_millis(250)), "250ms");
+/// assert_eq!(format_duration(Duration::ZERO), "0s");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    const UNITS: &[(u128, &str)] = &[
+        (3_600_000_000_000, "h"),
+        (60_000_000_000, "m"),
+        (1_000_000_000, "s"),
+        (1_000_000, "ms"),
+        (1_000, "us"),
+        (1, "ns"),
+    ];
+
+    let mut nanos = duration.as_nanos();
+    let mut parts = Vec::new();
+
+    for &(unit_nanos, suffix) in UNITS {
+        let value = nanos / unit_nanos;
+        if value > 0 {
+            parts.push(format!("{value}{suffix}"));
+            nanos %= unit_nanos;
+        }
+    }
+
+    if parts.is_empty() {
+        "0s".to_owned()
+    } else {
+        parts.join(" ")
+    }
+}
+
+/// Formats a [`CharDurations`] as `default <dur>, '<char>' <dur>, ...`, parseable back via
+/// [`FromStr`].
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::CharDurations;
+/// use std::time::Duration;
+/// use std::collections::HashMap;
+///
+/// let d = CharDurations::new(
+///     Duration::from_millis(90),
+///     HashMap::from([(' ', Duration::from_millis(250)), ('.', Duration::from_secs(1))]),
+/// );
+/// assert_eq!(d.to_string(), "default 90ms, ' ' 250ms, '.' 1s");
+///
+/// let round_tripped: CharDurations = d.to_string().parse().unwrap();
+/// assert_eq!(round_tripped, d);
+/// ```
+impl fmt::Display for CharDurations {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "default {}", format_duration(self.default_duration))?;
+
+        let mut entries: Vec<_> = self.specific_duration.iter().collect();
+        entries.sort_by_key(|(ch, _)| **ch);
+
+        for (ch, dur) in entries {
+            write!(f, ", '")?;
+            write_escaped_char(*ch, f)?;
+            write!(f, "' {}", format_duration(*dur))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_reports_invalid_character_offset() {
+        assert_eq!(
+            parse_duration("5@ms"),
+            Err(ParseDurationError::InvalidCharacter { offset: 1 })
+        );
+    }
+
+    #[test]
+    fn parse_duration_reports_missing_number_offset() {
+        assert_eq!(
+            parse_duration("ms"),
+            Err(ParseDurationError::MissingNumber { offset: 0 })
+        );
+    }
+
+    #[test]
+    fn parse_duration_reports_unknown_unit_span() {
+        assert_eq!(
+            parse_duration("5xyz"),
+            Err(ParseDurationError::UnknownUnit { start: 1, end: 4 })
+        );
+    }
+
+    #[test]
+    fn parse_duration_reports_overflow() {
+        assert_eq!(
+            parse_duration("99999999999999999999999999999999999999h"),
+            Err(ParseDurationError::Overflow)
+        );
+    }
+
+    #[test]
+    fn round_trips_quote_key() {
+        let d = CharDurations::new(
+            Duration::ZERO,
+            std::collections::HashMap::from([('\'', Duration::from_millis(10))]),
+        );
+        assert_eq!(d.to_string(), "default 0s, '\\'' 10ms");
+        assert_eq!(d.to_string().parse::<CharDurations>().unwrap(), d);
+    }
+
+    #[test]
+    fn round_trips_backslash_key() {
+        let d = CharDurations::new(
+            Duration::ZERO,
+            std::collections::HashMap::from([('\\', Duration::from_millis(10))]),
+        );
+        assert_eq!(d.to_string(), "default 0s, '\\\\' 10ms");
+        assert_eq!(d.to_string().parse::<CharDurations>().unwrap(), d);
+    }
+
+    #[test]
+    fn round_trips_control_char_key() {
+        let d = CharDurations::new(
+            Duration::ZERO,
+            std::collections::HashMap::from([('\n', Duration::from_millis(10))]),
+        );
+        assert_eq!(d.to_string(), "default 0s, '\\n' 10ms");
+        assert_eq!(d.to_string().parse::<CharDurations>().unwrap(), d);
+    }
+
+    #[test]
+    fn round_trips_comma_key() {
+        let d = CharDurations::new(
+            Duration::ZERO,
+            std::collections::HashMap::from([(',', Duration::from_millis(10))]),
+        );
+        assert_eq!(d.to_string(), "default 0s, ',' 10ms");
+        assert_eq!(d.to_string().parse::<CharDurations>().unwrap(), d);
+    }
+}
@@ -9,7 +9,7 @@
 //! use print_typewriter::{char_duration, println_typed};
 //!
 //! let duration = char_duration!(default 10.ms);
-//! println_typed!(duration, "hello");
+//! println_typed!(duration, "hello").unwrap();
 //! ```
 //!
 //! Typing "hello world" with each word being typed instantly and each space taking 250 milliesconds
@@ -18,7 +18,7 @@
 //! use print_typewriter::{char_duration, println_typed};
 //!
 //! let duration = char_duration!(' '->250.ms);
-//! println_typed!(duration, "hello world");
+//! println_typed!(duration, "hello world").unwrap();
 //! ```
 //!
 //! Typing a formatted string, "hello {} world" with spaces taking 250 milliseconds, periods taking 1 second, and everything else taking 90
@@ -28,17 +28,43 @@
 //!
 //! let duration = char_duration!(default 90.ms, ' '->250.ms, '.'->1.s);
 //! let beans = "beans";
-//! println_typed!(duration, "hello {} world", beans);
+//! println_typed!(duration, "hello {} world", beans).unwrap();
 //! ```
 
 use std::{
     collections::HashMap,
+    fmt,
     io::{self, Write},
     thread,
     time::Duration,
 };
+mod duration;
 mod macros;
 
+pub use duration::{format_duration, parse_duration, CharDurationsParseError, ParseDurationError};
+
+/// Error returned by [`Writer::print_typed`] when writing to or flushing the output stream fails.
+#[derive(Debug)]
+pub struct PrintError(io::Error);
+
+impl fmt::Display for PrintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to print typed output: {}", self.0)
+    }
+}
+
+impl std::error::Error for PrintError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+impl From<io::Error> for PrintError {
+    fn from(err: io::Error) -> Self {
+        PrintError(err)
+    }
+}
+
 /// A `CharDurations` type to represent how long [`Writer::print_typed`] should take
 /// to print out the inputted [`String`]
 ///
@@ -159,17 +185,24 @@ impl CharDurations {
 ///
 /// let chat_durations = CharDurations::new(ten_millis, HashMap::new());
 ///
-/// Writer::print_typed(&chat_durations, &"hello".to_owned());
+/// Writer::print_typed(&chat_durations, &"hello".to_owned()).unwrap();
 ///
 /// ```
 pub struct Writer;
 
 impl Writer {
-    /// Prints a character one at a time, flushing [`Stdout`] after every print.
+    /// Prints a character one at a time to the given sink, flushing it after every print.
     ///
     /// Uses the provided [`CharDurations`] to determine how long to wait between characters
-    /// and blocks the current thread for that duration. If flushing a character does not succeed,
-    /// printing will exit early with the message "Failed to flush stdout" printed.
+    /// and blocks the current thread for that duration. Returns the underlying [`io::Error`] if
+    /// writing to or flushing `out` fails, without typing out the remainder of `s`.
+    ///
+    /// ANSI escape sequences (SGR colors, cursor movement, etc.) embedded in `s` are detected
+    /// and written out atomically in a single flush with no delay, so styling doesn't get typed
+    /// out byte-by-byte or have the animation pause mid-sequence. Typing resumes at the next
+    /// printable character.
+    ///
+    /// [`Writer::print_typed`] is a thin wrapper over this method that targets [`Stdout`].
     ///
     /// [`Stdout`]: https://doc.rust-lang.org/1.67.0/std/io/struct.Stdout.html#method.flush
     ///
@@ -184,21 +217,71 @@ impl Writer {
     ///
     /// let chat_durations = CharDurations::new(ten_millis, HashMap::new());
     ///
-    /// Writer::print_typed(&chat_durations, &"hello".to_owned());
+    /// let mut out = Vec::new();
+    /// Writer::print_typed_to(&mut out, &chat_durations, "hello").unwrap();
+    /// assert_eq!(out, b"hello");
     ///
     /// ```
-    pub fn print_typed(durations: &CharDurations, str: &str) {
-        for l in str.chars() {
-            let wait_duration = durations.duration(l);
-            print!("{l}");
-            if let Ok(()) = io::stdout().flush() {
-                if wait_duration.as_millis() > 0 {
-                    thread::sleep(*wait_duration);
+    pub fn print_typed_to<W: Write>(out: &mut W, durations: &CharDurations, s: &str) -> io::Result<()> {
+        let mut chars = s.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c == '\u{1B}' {
+                let mut seq = String::from(c);
+
+                match chars.peek().copied() {
+                    Some('[') => {
+                        seq.push(chars.next().unwrap());
+                        while let Some(&next) = chars.peek() {
+                            chars.next();
+                            seq.push(next);
+                            if ('\u{40}'..='\u{7E}').contains(&next) {
+                                break;
+                            }
+                        }
+                    }
+                    Some(_) => seq.push(chars.next().unwrap()),
+                    None => {}
                 }
-            } else {
-                println!("Failed to flush stdout");
-                break;
+
+                write!(out, "{seq}")?;
+                out.flush()?;
+                continue;
+            }
+
+            let wait_duration = durations.duration(c);
+            write!(out, "{c}")?;
+            out.flush()?;
+            if !wait_duration.is_zero() {
+                thread::sleep(*wait_duration);
             }
         }
+
+        Ok(())
+    }
+
+    /// Prints a character one at a time, flushing [`Stdout`] after every print.
+    ///
+    /// A thin wrapper over [`Writer::print_typed_to`] targeting [`io::stdout`].
+    ///
+    /// [`Stdout`]: https://doc.rust-lang.org/1.67.0/std/io/struct.Stdout.html#method.flush
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use print_typewriter::{CharDurations, Writer};
+    /// use std::time::Duration;
+    /// use std::collections::HashMap;
+    ///
+    /// let ten_millis = Duration::from_millis(10);
+    ///
+    /// let chat_durations = CharDurations::new(ten_millis, HashMap::new());
+    ///
+    /// Writer::print_typed(&chat_durations, &"hello".to_owned()).unwrap();
+    ///
+    /// ```
+    pub fn print_typed(durations: &CharDurations, str: &str) -> Result<(), PrintError> {
+        Self::print_typed_to(&mut io::stdout(), durations, str)?;
+        Ok(())
     }
 }
@@ -46,6 +46,18 @@
 /// assert_eq!(*d.duration(' '), Duration::from_secs(1));
 /// assert_eq!(*d.duration('a'), Duration::ZERO);
 /// ```
+/// - Each of the three forms above also accepts human-friendly quoted duration strings in place of
+/// `.ms`/`.s` (an invalid string panics):
+///
+/// ```
+/// use print_typewriter::char_duration;
+/// use std::time::Duration;
+///
+/// let d = char_duration!(default "90ms", ' '->"250ms", '.'->"1s 500ms");
+/// assert_eq!(d.default_duration, Duration::from_millis(90));
+/// assert_eq!(*d.duration(' '), Duration::from_millis(250));
+/// assert_eq!(*d.duration('.'), Duration::from_millis(1500));
+/// ```
 ///
 /// [`CharDurations`]: crate::CharDurations
 /// [`char_duration`]: crate::char_duration
@@ -61,6 +73,15 @@ macro_rules! char_duration {
     ($( $char:literal->$char_duration:literal.$char_map_type:ident ),+) => {
         $crate::CharDurations::new(std::time::Duration::ZERO, std::collections::HashMap::from([$( ($char, $crate::map_type!($char_map_type)($char_duration)) ),+]))
     };
+    (default $duration:literal) => {
+        $crate::CharDurations::new($crate::parse_duration($duration).unwrap_or_else(|e| panic!("invalid duration string: {e}")), std::collections::HashMap::new())
+    };
+    (default $duration:literal, $( $char:literal->$char_duration:literal ),+) => {
+        $crate::CharDurations::new($crate::parse_duration($duration).unwrap_or_else(|e| panic!("invalid duration string: {e}")), std::collections::HashMap::from([$( ($char, $crate::parse_duration($char_duration).unwrap_or_else(|e| panic!("invalid duration string: {e}"))) ),+]))
+    };
+    ($( $char:literal->$char_duration:literal ),+) => {
+        $crate::CharDurations::new(std::time::Duration::ZERO, std::collections::HashMap::from([$( ($char, $crate::parse_duration($char_duration).unwrap_or_else(|e| panic!("invalid duration string: {e}"))) ),+]))
+    };
 }
 
 #[macro_export]
@@ -77,6 +98,9 @@ macro_rules! map_type {
 /// Prints a formatted string using the provided [`CharDurations`]
 /// Uses [`Writer::print_typed`] to print to the standard output one character at a time, with a newline.
 ///
+/// Expands to a call to [`Writer::print_typed`], so it evaluates to a `Result<(), PrintError>`
+/// that callers can `?` or `.unwrap()`.
+///
 /// # Examples
 ///
 /// - Printing "hello world" one word at a time
@@ -85,7 +109,7 @@ macro_rules! map_type {
 /// use print_typewriter::{char_duration, println_typed};
 ///
 /// let duration = char_duration!(' '->150.ms);
-/// println_typed!(duration, "hello world");
+/// println_typed!(duration, "hello world").unwrap();
 /// ```
 ///
 /// - Printing a formatted string, one character at a time
@@ -95,7 +119,7 @@ macro_rules! map_type {
 ///
 /// let example_variable = "beans";
 /// let duration = char_duration!(default 50.ms);
-/// println_typed!(duration, "hello {} world", example_variable);
+/// println_typed!(duration, "hello {} world", example_variable).unwrap();
 /// ```
 ///
 /// [`Writer::print_typed`]: struct.Writer.html#method.print_typed
@@ -107,7 +131,7 @@ macro_rules! println_typed {
         {
             let mut output = format!($($arg)*);
             output += "\n";
-            $crate::Writer::print_typed(&$duration, &output);
+            $crate::Writer::print_typed(&$duration, &output)
         }
     };
 }
@@ -115,6 +139,9 @@ macro_rules! println_typed {
 /// Prints a formatted string using the provided [`CharDurations`]
 /// Uses [`Writer::print_typed`] to print to the standard output one character at a time, without newline.
 ///
+/// Expands to a call to [`Writer::print_typed`], so it evaluates to a `Result<(), PrintError>`
+/// that callers can `?` or `.unwrap()`.
+///
 /// # Examples
 ///
 /// - Printing "hello world" one word at a time
@@ -123,7 +150,7 @@ macro_rules! println_typed {
 /// use print_typewriter::{char_duration, print_typed};
 ///
 /// let duration = char_duration!(' '->150.ms);
-/// print_typed!(duration, "hello world");
+/// print_typed!(duration, "hello world").unwrap();
 /// ```
 ///
 /// - Printing a formatted string, one character at a time
@@ -133,7 +160,7 @@ macro_rules! println_typed {
 ///
 /// let example_variable = "beans";
 /// let duration = char_duration!(default 50.ms);
-/// print_typed!(duration, "hello {} world", example_variable);
+/// print_typed!(duration, "hello {} world", example_variable).unwrap();
 /// ```
 ///
 /// [`Writer::print_typed`]: struct.Writer.html#method.print_typed
@@ -141,9 +168,61 @@ macro_rules! println_typed {
 ///
 #[macro_export]
 macro_rules! print_typed {
+    ($duration:tt, $($arg:tt)*) => {
+        $crate::Writer::print_typed(&$duration, &format!($($arg)*))
+    };
+}
+
+/// Prints a formatted string to standard error using the provided [`CharDurations`]
+/// Uses [`Writer::print_typed_to`] to print one character at a time, with a newline.
+///
+/// Expands to a call to [`Writer::print_typed_to`], so it evaluates to an `io::Result<()>`
+/// that callers can `?` or `.unwrap()`.
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::{char_duration, eprintln_typed};
+///
+/// let duration = char_duration!(' '->150.ms);
+/// eprintln_typed!(duration, "hello world").unwrap();
+/// ```
+///
+/// [`Writer::print_typed_to`]: struct.Writer.html#method.print_typed_to
+/// [`CharDurations`]: crate::CharDurations
+///
+#[macro_export]
+macro_rules! eprintln_typed {
     ($duration:tt, $($arg:tt)*) => {
         {
-            $crate::Writer::print_typed(&$duration, &format!($($arg)*));
+            let mut output = format!($($arg)*);
+            output += "\n";
+            $crate::Writer::print_typed_to(&mut std::io::stderr(), &$duration, &output)
         }
     };
 }
+
+/// Prints a formatted string to standard error using the provided [`CharDurations`]
+/// Uses [`Writer::print_typed_to`] to print one character at a time, without newline.
+///
+/// Expands to a call to [`Writer::print_typed_to`], so it evaluates to an `io::Result<()>`
+/// that callers can `?` or `.unwrap()`.
+///
+/// # Examples
+///
+/// ```
+/// use print_typewriter::{char_duration, eprint_typed};
+///
+/// let duration = char_duration!(' '->150.ms);
+/// eprint_typed!(duration, "hello world").unwrap();
+/// ```
+///
+/// [`Writer::print_typed_to`]: struct.Writer.html#method.print_typed_to
+/// [`CharDurations`]: crate::CharDurations
+///
+#[macro_export]
+macro_rules! eprint_typed {
+    ($duration:tt, $($arg:tt)*) => {
+        $crate::Writer::print_typed_to(&mut std::io::stderr(), &$duration, &format!($($arg)*))
+    };
+}